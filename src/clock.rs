@@ -1,6 +1,14 @@
 use crate::epoch::Epoch;
+
+#[cfg(feature = "std")]
+use ::std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8, Ordering};
+
+#[cfg(feature = "std")]
 use ::std::time::Duration;
 
+#[cfg(not(feature = "std"))]
+use ::core::time::Duration;
+
 /// # Monotonic Clock
 ///  A monotonic clock that can be anchored to a specific [Epoch].
 /// The clock is guaranteed to be monotonic, but not necessarily
@@ -10,13 +18,15 @@ use ::std::time::Duration;
 /// ## Thread safety
 /// The clock is thread safe.
 ///
-/// Eventually, we want to have network synchronization, but for now, we
-/// just use the system clock.
-/// TODO: Add network synchronization.
+/// Network synchronization (e.g. from NTP/PTP) is supported via
+/// [Clock::adjust], which slews the clock gradually instead of stepping it.
+/// Only a forward residual still unabsorbed after [MAX_SLEW_WINDOW] is
+/// stepped; a backward residual is absorbed by holding `now()` still, so
+/// `now()` never goes backwards.
 ///
 /// ## Example
 /// ```
-/// use monotonic_clock::Clock;
+/// use monotonic_clock::{Clock, MonotonicClock};
 /// use std::thread;
 /// use std::time::Duration;
 /// let clock = Clock::new();
@@ -26,7 +36,6 @@ use ::std::time::Duration;
 /// assert!(end - start >= Duration::from_millis(100));
 /// ```
 ///
-
 pub trait MonotonicClock {
     /// Return the epoch of the clock.
     fn epoch(&self) -> Epoch;
@@ -59,113 +68,704 @@ pub trait MonotonicClock {
     }
 }
 
-/// A monotonic clock that can be anchored to a specific [Epoch].
+/// A point in time produced by a [TimeSource].
+///
+/// This mirrors the handful of `std::time::Instant` methods the clock
+/// actually needs, so alternative time sources (e.g. a mock used in tests)
+/// can supply their own reference type instead of a real OS instant.
+pub trait TimeInstant: Copy + Clone + ::core::fmt::Debug + Ord + Send + Sync {
+    /// Returns the duration elapsed between `earlier` and `self`.
+    fn duration_since(&self, earlier: Self) -> Duration;
+
+    /// Like [TimeInstant::duration_since], but returns zero instead of
+    /// panicking or wrapping if `earlier` is actually later than `self`.
+    fn saturating_sub(&self, earlier: Self) -> Duration;
+}
+
+#[cfg(feature = "std")]
+impl TimeInstant for ::std::time::Instant {
+    #[inline]
+    fn duration_since(&self, earlier: Self) -> Duration {
+        ::std::time::Instant::duration_since(self, earlier)
+    }
+
+    #[inline]
+    fn saturating_sub(&self, earlier: Self) -> Duration {
+        ::std::time::Instant::saturating_duration_since(self, earlier)
+    }
+}
+
+/// A source of monotonic time samples that drives a [Clock].
+///
+/// The default [SystemTimeSource] wraps `std::time::Instant`. Tests that
+/// need deterministic control over elapsed time can supply a
+/// [MockTimeSource] instead, so time-dependent logic can be exercised
+/// without `thread::sleep`.
+pub trait TimeSource: Clone + ::core::fmt::Debug {
+    /// The instant type produced by this source.
+    type Instant: TimeInstant;
+
+    /// Returns the current instant.
+    fn now(&self) -> Self::Instant;
+}
+
+/// The default [TimeSource], backed by `std::time::Instant`.
+#[cfg(feature = "std")]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SystemTimeSource;
+
+#[cfg(feature = "std")]
+impl TimeSource for SystemTimeSource {
+    type Instant = ::std::time::Instant;
+
+    #[inline]
+    fn now(&self) -> Self::Instant {
+        ::std::time::Instant::now()
+    }
+}
+
+/// A [TimeSource] for tests: virtual time only advances when
+/// [MockTimeSource::advance] is called, so assertions on `now()`, `stop()`
+/// and `resume()` are deterministic instead of racing a real clock.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default)]
+pub struct MockTimeSource {
+    nanos: ::std::sync::Arc<::std::sync::atomic::AtomicU64>,
+}
+
+#[cfg(feature = "std")]
+impl MockTimeSource {
+    /// Creates a new mock time source starting at virtual time zero.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
 
+    /// Advances the virtual clock by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        self.nanos.fetch_add(duration.as_nanos() as u64, ::std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+#[cfg(feature = "std")]
+impl TimeSource for MockTimeSource {
+    type Instant = MockInstant;
+
+    #[inline]
+    fn now(&self) -> Self::Instant {
+        MockInstant(self.nanos.load(::std::sync::atomic::Ordering::SeqCst))
+    }
+}
+
+/// An instant produced by [MockTimeSource], counted in nanoseconds since
+/// the mock source was created.
+#[cfg(feature = "std")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MockInstant(u64);
+
+#[cfg(feature = "std")]
+impl TimeInstant for MockInstant {
+    #[inline]
+    fn duration_since(&self, earlier: Self) -> Duration {
+        Duration::from_nanos(self.0.saturating_sub(earlier.0))
+    }
+
+    #[inline]
+    fn saturating_sub(&self, earlier: Self) -> Duration {
+        Duration::from_nanos(self.0.saturating_sub(earlier.0))
+    }
+}
+
+/// An instant produced by [CoarseTimeSource], counted in nanoseconds since
+/// the background updater thread started.
+#[cfg(feature = "std")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CoarseInstant(u64);
+
+#[cfg(feature = "std")]
+impl TimeInstant for CoarseInstant {
+    #[inline]
+    fn duration_since(&self, earlier: Self) -> Duration {
+        Duration::from_nanos(self.0.saturating_sub(earlier.0))
+    }
+
+    #[inline]
+    fn saturating_sub(&self, earlier: Self) -> Duration {
+        Duration::from_nanos(self.0.saturating_sub(earlier.0))
+    }
+}
+
+/// A [TimeSource] backed by a single background thread that samples
+/// `Instant::now()` every `update_interval` and publishes the elapsed
+/// nanoseconds into an atomic, so that [TimeSource::now] becomes a single
+/// relaxed load with no syscall.
+///
+/// This trades precision for speed: readings are quantized to
+/// `update_interval`, so it suits code that samples time in tight loops
+/// (logging, metrics, rate accounting) rather than code that needs
+/// sub-interval accuracy. Successive reads never go backwards.
+#[cfg(feature = "std")]
 #[derive(Debug, Clone)]
-pub struct Clock {
-    inner: ::std::sync::Arc<::std::sync::RwLock<InnerClock>>,
+pub struct CoarseTimeSource {
+    inner: ::std::sync::Arc<CoarseInner>,
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug)]
+struct CoarseInner {
+    nanos: ::std::sync::Arc<::std::sync::atomic::AtomicU64>,
+    stop: ::std::sync::Arc<::std::sync::atomic::AtomicBool>,
+    handle: ::std::sync::Mutex<Option<::std::thread::JoinHandle<()>>>,
+}
+
+#[cfg(feature = "std")]
+impl CoarseTimeSource {
+    /// Spawns the background updater thread, sampling every `update_interval`.
+    pub fn new(update_interval: Duration) -> Self {
+        let nanos = ::std::sync::Arc::new(::std::sync::atomic::AtomicU64::new(0));
+        let stop = ::std::sync::Arc::new(::std::sync::atomic::AtomicBool::new(false));
+        let start = ::std::time::Instant::now();
+
+        let handle = {
+            let nanos = nanos.clone();
+            let stop = stop.clone();
+            ::std::thread::spawn(move || {
+                while !stop.load(::std::sync::atomic::Ordering::Relaxed) {
+                    ::std::thread::sleep(update_interval);
+                    if stop.load(::std::sync::atomic::Ordering::Relaxed) {
+                        break;
+                    }
+                    let elapsed = start.elapsed().as_nanos() as u64;
+                    nanos.store(elapsed, ::std::sync::atomic::Ordering::Relaxed);
+                }
+            })
+        };
+
+        Self {
+            inner: ::std::sync::Arc::new(CoarseInner { nanos, stop, handle: ::std::sync::Mutex::new(Some(handle)) }),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl TimeSource for CoarseTimeSource {
+    type Instant = CoarseInstant;
+
+    #[inline]
+    fn now(&self) -> Self::Instant {
+        CoarseInstant(self.inner.nanos.load(::std::sync::atomic::Ordering::Relaxed))
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::ops::Drop for CoarseInner {
+    fn drop(&mut self) {
+        self.stop.store(true, ::std::sync::atomic::Ordering::Relaxed);
+        if let Some(handle) = self.handle.lock().unwrap().take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl TimeInstant for Duration {
+    #[inline]
+    fn duration_since(&self, earlier: Self) -> Duration {
+        Duration::saturating_sub(*self, earlier)
+    }
+
+    #[inline]
+    fn saturating_sub(&self, earlier: Self) -> Duration {
+        Duration::saturating_sub(*self, earlier)
+    }
+}
+
+/// Which monotonic OS clock a [Clock] reads from when constructed via
+/// [Clock::with_clock_id].
+///
+/// There is deliberately no `Realtime` variant: `CLOCK_REALTIME` (and any
+/// other wall-clock source) is allowed to step backward under an NTP step
+/// correction, a leap second, or a manual `date -s`, which would break the
+/// monotonicity this type exists to guarantee. Use [Epoch::from_unix] for
+/// wall-clock time instead.
+#[cfg(feature = "std")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ClockId {
+    /// `CLOCK_MONOTONIC`: monotonic, but slewed (not stepped) by NTP.
+    Monotonic,
+    /// `CLOCK_MONOTONIC_RAW`: monotonic and unaffected by NTP adjustments.
+    MonotonicRaw,
+    /// `CLOCK_BOOTTIME`: monotonic, and keeps advancing while suspended.
+    Boottime,
+}
+
+/// A [TimeSource] that reads directly from a specific monotonic OS clock,
+/// selected by [ClockId], rather than always using `Instant`.
+///
+/// On Linux and macOS this calls `clock_gettime(2)` with the matching clock
+/// constant. On WASI it maps to `__wasi_clock_time_get`. On every other
+/// platform - including other Unix-likes this crate doesn't special-case,
+/// such as the BSDs - it falls back to `Instant`, so all three [ClockId]
+/// variants are always available, they just collapse onto the same
+/// underlying clock where the OS doesn't distinguish them.
+#[cfg(feature = "std")]
+#[derive(Debug, Copy, Clone)]
+pub struct ClockIdTimeSource {
+    id: ClockId,
+}
+
+#[cfg(feature = "std")]
+impl ClockIdTimeSource {
+    /// Creates a time source that reads from the given OS clock.
+    #[inline]
+    pub fn new(id: ClockId) -> Self {
+        Self { id }
+    }
 }
 
-unsafe impl Sync for Clock {}
-unsafe impl Send for Clock {}
+#[cfg(feature = "std")]
+impl TimeSource for ClockIdTimeSource {
+    type Instant = Duration;
+
+    #[inline]
+    fn now(&self) -> Self::Instant {
+        clock_id_now(self.id)
+    }
+}
+
+// Only Linux and macOS get the hand-written clock_gettime(2) constants below;
+// every other Unix-like (the BSDs, Android, iOS, illumos, ...) falls through
+// to the portable `Instant`-based fallback below.
+#[cfg(all(feature = "std", any(target_os = "linux", target_os = "macos")))]
+mod sys_clock {
+    #[repr(C)]
+    pub(super) struct timespec {
+        pub tv_sec: i64,
+        pub tv_nsec: i64,
+    }
+
+    extern "C" {
+        pub(super) fn clock_gettime(clock_id: i32, tp: *mut timespec) -> i32;
+    }
+
+    #[cfg(target_os = "linux")]
+    pub(super) const CLOCK_MONOTONIC: i32 = 1;
+    #[cfg(target_os = "macos")]
+    pub(super) const CLOCK_MONOTONIC: i32 = 6;
+    #[cfg(target_os = "linux")]
+    pub(super) const CLOCK_MONOTONIC_RAW: i32 = 4;
+    #[cfg(target_os = "macos")]
+    pub(super) const CLOCK_MONOTONIC_RAW: i32 = 4;
+    #[cfg(target_os = "linux")]
+    pub(super) const CLOCK_BOOTTIME: i32 = 7;
+    // macOS has no CLOCK_BOOTTIME; CLOCK_MONOTONIC is the closest available.
+    #[cfg(target_os = "macos")]
+    pub(super) const CLOCK_BOOTTIME: i32 = CLOCK_MONOTONIC;
+}
+
+#[cfg(all(feature = "std", any(target_os = "linux", target_os = "macos")))]
+fn clock_id_now(id: ClockId) -> Duration {
+    let raw = match id {
+        ClockId::Monotonic => sys_clock::CLOCK_MONOTONIC,
+        ClockId::MonotonicRaw => sys_clock::CLOCK_MONOTONIC_RAW,
+        ClockId::Boottime => sys_clock::CLOCK_BOOTTIME,
+    };
+    let mut ts = sys_clock::timespec { tv_sec: 0, tv_nsec: 0 };
+    let rc = unsafe { sys_clock::clock_gettime(raw, &mut ts) };
+    assert_eq!(rc, 0, "clock_gettime({raw}) failed");
+    Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32)
+}
+
+#[cfg(all(feature = "std", target_os = "wasi"))]
+mod sys_clock_wasi {
+    #[link(wasm_import_module = "wasi_snapshot_preview1")]
+    extern "C" {
+        pub(super) fn clock_time_get(id: u32, precision: u64, time: *mut u64) -> u16;
+    }
+
+    pub(super) const CLOCKID_MONOTONIC: u32 = 1;
+}
+
+#[cfg(all(feature = "std", target_os = "wasi"))]
+fn clock_id_now(id: ClockId) -> Duration {
+    let raw = match id {
+        ClockId::Monotonic | ClockId::MonotonicRaw | ClockId::Boottime => sys_clock_wasi::CLOCKID_MONOTONIC,
+    };
+    let mut nanos: u64 = 0;
+    let errno = unsafe { sys_clock_wasi::clock_time_get(raw, 1, &mut nanos) };
+    assert_eq!(errno, 0, "__wasi_clock_time_get({raw}) failed");
+    Duration::from_nanos(nanos)
+}
+
+#[cfg(all(feature = "std", not(any(target_os = "linux", target_os = "macos", target_os = "wasi"))))]
+fn clock_id_now(id: ClockId) -> Duration {
+    match id {
+        ClockId::Monotonic | ClockId::MonotonicRaw | ClockId::Boottime => {
+            static START: ::std::sync::OnceLock<::std::time::Instant> = ::std::sync::OnceLock::new();
+            let start = *START.get_or_init(::std::time::Instant::now);
+            ::std::time::Instant::now().duration_since(start)
+        }
+    }
+}
+
+/// The direction of a correction applied via [Clock::adjust].
+#[cfg(feature = "std")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Sign {
+    /// The clock is behind the reference time and must speed up to catch up.
+    Positive,
+    /// The clock is ahead of the reference time and must slow down to settle.
+    Negative,
+}
+
+/// Nominal slew correction rate, in parts-per-million, used by [Clock::adjust].
+#[cfg(feature = "std")]
+pub const DEFAULT_SLEW_PPM: u32 = 20;
+
+/// Maximum slew correction rate, in parts-per-million, that [Clock::adjust_with_rate]
+/// will apply. Rates above this are clamped.
+#[cfg(feature = "std")]
+pub const MAX_SLEW_PPM: u32 = 200;
+
+/// Maximum duration over which a slew correction is spread. A forward
+/// residual still unabsorbed at its end is stepped; a backward one is
+/// absorbed by holding `now()` still until raw time has caught up.
+#[cfg(feature = "std")]
+pub const MAX_SLEW_WINDOW: Duration = Duration::from_secs(90 * 60);
+
+/// An in-progress slew correction being applied to an [InnerClock]'s `now()`.
+#[cfg(feature = "std")]
+#[derive(Debug, Copy, Clone)]
+struct Slew {
+    sign: Sign,
+    rate_ppm: u32,
+    // The raw (pre-slew) elapsed duration and the corresponding virtual
+    // `now()` value observed when this slew began.
+    anchor_raw: Duration,
+    anchor_now: Duration,
+    // How long the slew runs for, and any offset left over once it ends,
+    // stepped instantly because it could not be absorbed within the window
+    // (for a Negative slew, absorbed by holding `now()` still instead).
+    window: Duration,
+    residual: Duration,
+}
+
+#[cfg(feature = "std")]
+impl Slew {
+    fn correction_at(t: Duration, rate_ppm: u32) -> Duration {
+        Duration::from_secs_f64(t.as_secs_f64() * rate_ppm as f64 / 1_000_000.0)
+    }
 
-impl Clock {
-    /// Create a new clock.
+    fn new(sign: Sign, rate_ppm: u32, offset: Duration, anchor_raw: Duration, anchor_now: Duration) -> Self {
+        let rate_ppm = rate_ppm.min(MAX_SLEW_PPM);
+        let needed_window = if rate_ppm == 0 {
+            MAX_SLEW_WINDOW
+        } else {
+            Duration::from_secs_f64(offset.as_secs_f64() * 1_000_000.0 / rate_ppm as f64)
+        };
+        let window = needed_window.min(MAX_SLEW_WINDOW);
+        let residual = offset.saturating_sub(Self::correction_at(window, rate_ppm));
+        Self { sign, rate_ppm, anchor_raw, anchor_now, window, residual }
+    }
+
+    /// The total correction applied once the slew has run to completion.
+    fn total_correction(&self) -> Duration {
+        Self::correction_at(self.window, self.rate_ppm) + self.residual
+    }
+
+    /// Applies this slew to `raw`, the current raw (pre-slew) elapsed duration.
+    fn apply(&self, raw: Duration) -> Duration {
+        let t = raw.saturating_sub(self.anchor_raw);
+        let correction = if t >= self.window { self.total_correction() } else { Self::correction_at(t, self.rate_ppm) };
+        match self.sign {
+            Sign::Positive => self.anchor_now + t + correction,
+            // Stepping a negative residual would move `now()` backwards, so
+            // `now()` holds at its end-of-window value until raw time has
+            // absorbed the residual.
+            Sign::Negative => {
+                let held = t.min(self.window);
+                let floor = (self.anchor_now + held).saturating_sub(Self::correction_at(held, self.rate_ppm));
+                (self.anchor_now + t).saturating_sub(correction).max(floor)
+            }
+        }
+    }
+
+    /// The correction still left to absorb at the given raw elapsed duration.
+    fn remaining(&self, raw: Duration) -> Duration {
+        let t = raw.saturating_sub(self.anchor_raw);
+        if t >= self.window {
+            match self.sign {
+                Sign::Positive => Duration::new(0, 0),
+                Sign::Negative => self.residual.saturating_sub(t - self.window),
+            }
+        } else {
+            self.total_correction().saturating_sub(Self::correction_at(t, self.rate_ppm))
+        }
+    }
+
+    fn is_active(&self, raw: Duration) -> bool {
+        raw.saturating_sub(self.anchor_raw) < self.window || !self.remaining(raw).is_zero()
+    }
+}
+
+/// A monotonic clock that can be anchored to a specific [Epoch].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct Clock<T: TimeSource = SystemTimeSource> {
+    inner: ::std::sync::Arc<InnerClock<T>>,
+}
+
+#[cfg(feature = "std")]
+unsafe impl<T: TimeSource + Sync> Sync for Clock<T> {}
+#[cfg(feature = "std")]
+unsafe impl<T: TimeSource + Send + Sync> Send for Clock<T> {}
+
+#[cfg(feature = "std")]
+impl Clock<SystemTimeSource> {
+    /// Create a new clock backed by the system clock.
     pub fn new() -> Self {
+        Self::with_source(SystemTimeSource)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clock<ClockIdTimeSource> {
+    /// Create a clock reading directly from the given OS clock. See
+    /// [ClockIdTimeSource] for platform support.
+    pub fn with_clock_id(id: ClockId) -> Self {
+        Self::with_source(ClockIdTimeSource::new(id))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clock<CoarseTimeSource> {
+    /// Create a clock backed by a background updater thread that samples
+    /// the system clock every `update_interval` (typically ~4-25ms).
+    ///
+    /// `now()` becomes a single relaxed atomic load instead of an OS
+    /// syscall, at the cost of quantizing readings to `update_interval`.
+    /// It's a drop-in replacement for [Clock::new] wherever that trade-off
+    /// is acceptable.
+    pub fn coarse(update_interval: Duration) -> Self {
+        Self::with_source(CoarseTimeSource::new(update_interval))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: TimeSource> Clock<T> {
+    /// Create a new clock driven by the given [TimeSource].
+    pub fn with_source(source: T) -> Self {
         Self {
-            inner: ::std::sync::Arc::new(::std::sync::RwLock::new(InnerClock::new())),
+            inner: ::std::sync::Arc::new(InnerClock::new(source)),
         }
     }
 
     /// Start the clock.
     pub fn start(&self) {
-        self.inner.write().unwrap().start();
+        self.inner.start();
     }
 
     /// Stop the clock.
     pub fn stop(&self) -> Option<Duration> {
-        self.inner.write().unwrap().stop()
+        self.inner.stop()
     }
 
     /// Reset the clock.
     pub fn reset(&self) {
-        self.inner.write().unwrap().reset();
+        self.inner.reset();
     }
 
     /// Resume a paused clock.
     pub fn resume(&self) -> Option<Duration> {
-        self.inner.write().unwrap().resume()
+        self.inner.resume()
+    }
+
+    /// Gradually correct the clock by `offset` in the given direction,
+    /// using the nominal [DEFAULT_SLEW_PPM] rate.
+    ///
+    /// Rather than stepping `now()` discontinuously, the correction is
+    /// applied as a bounded frequency slew: intended to be driven by an
+    /// external time source (e.g. NTP/PTP) feeding in the observed offset.
+    pub fn adjust(&self, offset: Duration, sign: Sign) {
+        self.adjust_with_rate(offset, sign, DEFAULT_SLEW_PPM);
+    }
+
+    /// Like [Clock::adjust], but with an explicit slew rate in parts-per-million,
+    /// clamped to [MAX_SLEW_PPM].
+    pub fn adjust_with_rate(&self, offset: Duration, sign: Sign, rate_ppm: u32) {
+        self.inner.adjust(offset, sign, rate_ppm);
+    }
+
+    /// Returns true if the clock is currently slewing to absorb a correction
+    /// applied via [Clock::adjust].
+    pub fn is_slewing(&self) -> bool {
+        self.inner.is_slewing()
+    }
+
+    /// Returns the correction still left to absorb from the most recent
+    /// [Clock::adjust] call, or zero if the clock isn't slewing.
+    pub fn remaining_correction(&self) -> Duration {
+        self.inner.remaining_correction()
     }
 }
 
-impl MonotonicClock for Clock {
+#[cfg(feature = "std")]
+impl<T: TimeSource> MonotonicClock for Clock<T> {
     fn epoch(&self) -> Epoch {
-        self.inner.read().unwrap().epoch()
+        self.inner.epoch()
     }
 
     fn now(&self) -> Duration {
-        self.inner.read().unwrap().now()
+        self.inner.now()
     }
 
     fn is_ticking(&self) -> bool {
-        self.inner.read().unwrap().is_ticking()
+        self.inner.is_ticking()
     }
 }
 
-impl Default for Clock {
+#[cfg(feature = "std")]
+impl Default for Clock<SystemTimeSource> {
     #[inline]
     fn default() -> Self {
         Self::new()
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct InnerClock {
-    epoch: Epoch, // The unix_epoch time at which the clock was created.
-    start: ::std::time::Instant,
-    stop: Option<::std::time::Instant>,
+// `state` transitions Running -> Stopping -> Stopped on `stop()`, and
+// Stopped -> Resuming -> Running on `resume()`. Splitting "not running" from
+// "stopped_at_nanos is valid" into separate states - with the relevant
+// atomic write happening only in between - means a reader can never observe
+// an authoritative-looking stop while the frozen value it reads is stale:
+// Stopping still reads the live clock (`stopped_at_nanos` isn't written yet)
+// and Resuming stays "still frozen at stopped_at_nanos" in
+// `effective_raw_nanos()`, so a reader never sees the `RUNNING`-implied
+// `raw_nanos()` path until `base_nanos` has actually been caught up.
+#[cfg(feature = "std")]
+const RUNNING: u8 = 0;
+#[cfg(feature = "std")]
+const STOPPING: u8 = 1;
+#[cfg(feature = "std")]
+const STOPPED: u8 = 2;
+#[cfg(feature = "std")]
+const RESUMING: u8 = 3;
+
+/// Lock-free shared state behind a [Clock].
+///
+/// `origin` is a single raw instant sampled once at construction and never
+/// mutated again, so reading it never needs synchronization. Everything
+/// that can change afterwards - whether the clock is running, where its
+/// "zero" is, and any in-progress slew - lives in atomics (or, for the
+/// rarely-written slew parameters, a lock that the hot `now()` path only
+/// takes once a slew has been applied since the last `start()`/`reset()`).
+#[cfg(feature = "std")]
+#[derive(Debug)]
+struct InnerClock<T: TimeSource> {
+    source: T,
+    origin: T::Instant,
+    // Nanoseconds-since-`origin` epoch value, mutated by `reset()`.
+    epoch_nanos: AtomicU64,
+    // Nanoseconds-since-`origin` treated as this clock's zero point,
+    // mutated by `start()`/`reset()`/`resume()`.
+    base_nanos: AtomicU64,
+    // RUNNING, STOPPING, STOPPED or RESUMING; see the constants above.
+    state: AtomicU8,
+    // Nanoseconds-since-`origin` latched at the moment `stop()` was called;
+    // only authoritative while `state` is STOPPED (and, transiently, RESUMING).
+    stopped_at_nanos: AtomicU64,
+    // Fast-path hint so `now()` can skip the slew lock entirely until
+    // `adjust()` is first called; cleared only by `start()`/`reset()`.
+    slewing: AtomicBool,
+    slew: ::std::sync::RwLock<Option<Slew>>,
 }
 
-impl InnerClock {
-    /// Create a new monotonic clock.
+#[cfg(feature = "std")]
+impl<T: TimeSource> InnerClock<T> {
+    /// Create a new monotonic clock driven by `source`.
     #[inline]
-    pub fn new() -> Self {
+    pub fn new(source: T) -> Self {
+        let origin = source.now();
         Self {
-            epoch: Epoch::from_unix(),
-            start: ::std::time::Instant::now(),
-            stop: None,
+            source,
+            origin,
+            epoch_nanos: AtomicU64::new(Self::epoch_to_nanos(Epoch::from_unix())),
+            base_nanos: AtomicU64::new(0),
+            state: AtomicU8::new(RUNNING),
+            stopped_at_nanos: AtomicU64::new(0),
+            slewing: AtomicBool::new(false),
+            slew: ::std::sync::RwLock::new(None),
+        }
+    }
+
+    fn epoch_to_nanos(epoch: Epoch) -> u64 {
+        Duration::from(epoch).as_nanos() as u64
+    }
+
+    /// Raw nanoseconds elapsed since `origin`, ignoring whether the clock is stopped.
+    #[inline]
+    fn raw_nanos(&self) -> u64 {
+        self.source.now().duration_since(self.origin).as_nanos() as u64
+    }
+
+    /// Raw nanoseconds elapsed since `origin`, frozen at `stopped_at_nanos`
+    /// while the clock is STOPPED, and still frozen through RESUMING (until
+    /// `resume()` has finished folding the paused interval into
+    /// `base_nanos`). STOPPING is *not* frozen: `stopped_at_nanos` isn't
+    /// authoritative yet at that point, so this still reads the live clock.
+    #[inline]
+    fn effective_raw_nanos(&self) -> u64 {
+        match self.state.load(Ordering::SeqCst) {
+            STOPPED | RESUMING => self.stopped_at_nanos.load(Ordering::SeqCst),
+            _ => self.raw_nanos(),
         }
     }
 
     /// Returns the epoch of the clock.
     #[inline]
     pub fn epoch(&self) -> Epoch {
-        self.epoch
+        Epoch::from(Duration::from_nanos(self.epoch_nanos.load(Ordering::SeqCst)))
     }
 
     /// Reset the clock to zero.
     #[inline]
-    pub fn reset(&mut self) {
-        self.epoch = Epoch::from_unix();
-        self.start = ::std::time::Instant::now();
-        self.stop = None;
+    pub fn reset(&self) {
+        self.epoch_nanos.store(Self::epoch_to_nanos(Epoch::from_unix()), Ordering::SeqCst);
+        self.base_nanos.store(self.raw_nanos(), Ordering::SeqCst);
+        self.state.store(RUNNING, Ordering::SeqCst);
+        self.slewing.store(false, Ordering::SeqCst);
+        *self.slew.write().unwrap() = None;
     }
 
     /// Start the clock.
     #[inline]
-    pub fn start(&mut self) {
-        self.start = ::std::time::Instant::now();
-        self.stop = None;
+    pub fn start(&self) {
+        self.base_nanos.store(self.raw_nanos(), Ordering::SeqCst);
+        self.state.store(RUNNING, Ordering::SeqCst);
+        self.slewing.store(false, Ordering::SeqCst);
+        *self.slew.write().unwrap() = None;
     }
 
-    /// Resumes paused clock.
-    /// If the clock is not stopped, this does nothing.
-
+    /// Resumes a paused clock, excluding the paused interval from `now()` -
+    /// i.e. `now()` right after `resume()` reads the same as it did right
+    /// before the matching `stop()`. Returns the duration the clock was
+    /// paused for. If the clock is not stopped, this does nothing.
     #[inline]
-    pub fn resume(&mut self) -> Option<Duration> {
-        if let Some(stop) = self.stop {
-            self.stop = None;
-            ::std::time::Instant::now().checked_duration_since(stop)
+    pub fn resume(&self) -> Option<Duration> {
+        // Move through RESUMING (still frozen at `stopped_at_nanos`, same as
+        // STOPPED - see `effective_raw_nanos`) while `base_nanos` is caught
+        // up, only publishing RUNNING once that's done. Otherwise a reader
+        // could observe `state == RUNNING` with a stale `base_nanos` and
+        // momentarily see `now()` jump by the whole paused interval before
+        // dropping back down.
+        let was_stopped = self.state.compare_exchange(STOPPED, RESUMING, Ordering::SeqCst, Ordering::SeqCst).is_ok();
+        if was_stopped {
+            // Read only after winning the CAS: `stopped_at_nanos` is written
+            // only in STOPPING, so it can't change under us while RESUMING.
+            let stopped_at = self.stopped_at_nanos.load(Ordering::SeqCst);
+            let paused = self.raw_nanos().saturating_sub(stopped_at);
+            self.base_nanos.fetch_add(paused, Ordering::SeqCst);
+            self.state.store(RUNNING, Ordering::SeqCst);
+            Some(Duration::from_nanos(paused))
         } else {
             Some(Duration::new(0, 0))
         }
@@ -174,41 +774,87 @@ impl InnerClock {
     /// Stop the clock if it's running, otherwise does nothing.
     /// Returns the duration the clock was running.
     #[inline]
-    pub fn stop(&mut self) -> Option<Duration> {
-        if self.stop.is_none() {
-            self.stop = Some(::std::time::Instant::now());
+    pub fn stop(&self) -> Option<Duration> {
+        let raw = self.raw_nanos();
+        // Publish `stopped_at_nanos` *before* the state that makes it
+        // authoritative (STOPPED), so a concurrent `effective_raw_nanos()`
+        // never observes the frozen state while `stopped_at_nanos` still
+        // holds a stale value from a previous stop/resume cycle.
+        if self.state.compare_exchange(RUNNING, STOPPING, Ordering::SeqCst, Ordering::SeqCst).is_ok() {
+            self.stopped_at_nanos.store(raw, Ordering::SeqCst);
+            self.state.store(STOPPED, Ordering::SeqCst);
         }
-        self.stop.map(|stop| stop - self.start)
+        let stopped_at = self.stopped_at_nanos.load(Ordering::SeqCst);
+        Some(Duration::from_nanos(stopped_at.saturating_sub(self.base_nanos.load(Ordering::SeqCst))))
+    }
+
+    /// The raw, pre-slew elapsed duration since the clock started.
+    #[inline]
+    fn raw_elapsed(&self) -> Duration {
+        let base = self.base_nanos.load(Ordering::SeqCst);
+        Duration::from_nanos(self.effective_raw_nanos().saturating_sub(base))
     }
 
     /// Get duration since the clock has been running time.
     #[inline]
     pub fn now(&self) -> Duration {
-        if let Some(stop) = self.stop {
-            stop.duration_since(self.start)
-        } else {
-            ::std::time::Instant::now().duration_since(self.start)
+        let raw = self.raw_elapsed();
+        if self.slewing.load(Ordering::SeqCst) {
+            if let Some(slew) = &*self.slew.read().unwrap() {
+                return slew.apply(raw);
+            }
         }
+        raw
     }
 
     /// Is the clock running?
     #[inline]
     pub fn is_ticking(&self) -> bool {
-        self.stop.is_none()
+        !matches!(self.state.load(Ordering::SeqCst), STOPPED | RESUMING)
     }
-}
 
-impl Default for InnerClock {
+    /// Gradually correct the clock by `offset`, applying at most `rate_ppm`
+    /// parts-per-million until the offset is absorbed or [MAX_SLEW_WINDOW]
+    /// elapses, after which a forward residual is stepped and a backward one
+    /// is absorbed by holding `now()` still.
     #[inline]
-    fn default() -> Self {
-        Self::new()
+    pub fn adjust(&self, offset: Duration, sign: Sign, rate_ppm: u32) {
+        let anchor_raw = self.raw_elapsed();
+        let anchor_now = self.now();
+        *self.slew.write().unwrap() = Some(Slew::new(sign, rate_ppm, offset, anchor_raw, anchor_now));
+        self.slewing.store(true, Ordering::SeqCst);
+    }
+
+    /// Is the clock currently slewing to absorb a correction?
+    #[inline]
+    pub fn is_slewing(&self) -> bool {
+        if !self.slewing.load(Ordering::SeqCst) {
+            return false;
+        }
+        match &*self.slew.read().unwrap() {
+            Some(slew) => slew.is_active(self.raw_elapsed()),
+            None => false,
+        }
+    }
+
+    /// The correction still left to absorb, or zero if not slewing.
+    #[inline]
+    pub fn remaining_correction(&self) -> Duration {
+        if !self.slewing.load(Ordering::SeqCst) {
+            return Duration::new(0, 0);
+        }
+        match &*self.slew.read().unwrap() {
+            Some(slew) => slew.remaining(self.raw_elapsed()),
+            None => Duration::new(0, 0),
+        }
     }
 }
 
-impl MonotonicClock for InnerClock {
+#[cfg(feature = "std")]
+impl<T: TimeSource> MonotonicClock for InnerClock<T> {
     #[inline]
     fn epoch(&self) -> Epoch {
-        self.epoch
+        self.epoch()
     }
 
     #[inline]
@@ -222,23 +868,26 @@ impl MonotonicClock for InnerClock {
     }
 }
 
-impl ::std::fmt::Display for InnerClock {
+#[cfg(feature = "std")]
+impl<T: TimeSource> ::std::fmt::Display for InnerClock<T> {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         write!(f, "{}", self.time_as_float())
     }
 }
 
-impl ::std::convert::From<InnerClock> for Duration {
+#[cfg(feature = "std")]
+impl<T: TimeSource> ::std::convert::From<InnerClock<T>> for Duration {
     /// Get the now time since the clock's epoch.
-    fn from(mc: InnerClock) -> Self {
+    fn from(mc: InnerClock<T>) -> Self {
         mc.time()
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
     use assert2::assert;
+
     #[test]
     fn test_monotonic_clock() {
         let clock = Clock::new();
@@ -268,4 +917,170 @@ mod tests {
         eprintln!("clock.epoch = {:?}", clock.epoch());
         eprintln!("clock.now() = {:?}", clock.time());
     }
+
+    #[test]
+    fn test_mock_time_source_deterministic() {
+        let source = MockTimeSource::new();
+        let clock = Clock::with_source(source.clone());
+
+        assert!(clock.now() == Duration::new(0, 0));
+
+        source.advance(Duration::from_secs(5));
+        assert!(clock.now() == Duration::from_secs(5));
+
+        let stopped_at = clock.stop().unwrap();
+        assert!(stopped_at == Duration::from_secs(5));
+        source.advance(Duration::from_secs(5));
+        assert!(clock.now() == stopped_at);
+
+        clock.resume();
+        source.advance(Duration::from_secs(1));
+        assert!(clock.now() == Duration::from_secs(6));
+    }
+
+    #[test]
+    fn test_resume_excludes_paused_duration() {
+        let source = MockTimeSource::new();
+        let clock = Clock::with_source(source.clone());
+
+        source.advance(Duration::from_secs(3));
+        let before_stop = clock.now();
+        clock.stop();
+
+        source.advance(Duration::from_secs(100));
+        let paused_for = clock.resume().unwrap();
+        assert!(paused_for == Duration::from_secs(100));
+
+        // None of the 100s spent stopped is credited to `now()`.
+        assert!(clock.now() == before_stop);
+    }
+
+    #[test]
+    fn test_adjust_slews_monotonically_towards_offset() {
+        let source = MockTimeSource::new();
+        let clock = Clock::with_source(source.clone());
+
+        // Ask the clock to catch up by 10ms at MAX_SLEW_PPM, the fastest allowed rate.
+        clock.adjust_with_rate(Duration::from_millis(10), Sign::Positive, MAX_SLEW_PPM);
+        assert!(clock.is_slewing());
+
+        let mut previous = clock.now();
+        for _ in 0..20 {
+            source.advance(Duration::from_secs(1));
+            let now = clock.now();
+            assert!(now > previous);
+            previous = now;
+        }
+
+        // MAX_SLEW_PPM is 0.02%, so 20s of slewing absorbs at most 4ms; the
+        // clock must still be catching up.
+        assert!(clock.is_slewing());
+        assert!(clock.remaining_correction() > Duration::new(0, 0));
+
+        // Run past the slew window and confirm the offset is fully absorbed.
+        source.advance(MAX_SLEW_WINDOW);
+        assert!(!clock.is_slewing());
+        assert!(clock.remaining_correction() == Duration::new(0, 0));
+    }
+
+    #[test]
+    fn test_coarse_clock_never_goes_backwards() {
+        let clock = Clock::coarse(Duration::from_millis(5));
+
+        let mut previous = clock.now();
+        for _ in 0..5 {
+            ::std::thread::sleep(Duration::from_millis(20));
+            let now = clock.now();
+            assert!(now >= previous);
+            previous = now;
+        }
+        assert!(previous > Duration::new(0, 0));
+    }
+
+    #[test]
+    fn test_concurrent_now_stays_monotonic_under_stop_resume() {
+        let clock = Clock::new();
+
+        let toggler = {
+            let clock = clock.clone();
+            ::std::thread::spawn(move || {
+                for _ in 0..200 {
+                    clock.stop();
+                    clock.resume();
+                }
+            })
+        };
+
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                let clock = clock.clone();
+                ::std::thread::spawn(move || {
+                    let mut previous = clock.now();
+                    for _ in 0..5000 {
+                        let now = clock.now();
+                        assert!(now >= previous);
+                        previous = now;
+                    }
+                })
+            })
+            .collect();
+
+        toggler.join().unwrap();
+        for reader in readers {
+            reader.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_stop_never_exposes_a_stale_frozen_time() {
+        // Hammers stop()/resume() from several threads while several readers
+        // race them, to catch the publication-order race between
+        // `stopped_at_nanos` and the flag that makes it authoritative: a
+        // reader must never see a frozen time older than one it already
+        // observed.
+        let clock = Clock::new();
+
+        let togglers: Vec<_> = (0..4)
+            .map(|_| {
+                let clock = clock.clone();
+                ::std::thread::spawn(move || {
+                    for _ in 0..2000 {
+                        clock.stop();
+                        clock.resume();
+                    }
+                })
+            })
+            .collect();
+
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                let clock = clock.clone();
+                ::std::thread::spawn(move || {
+                    let mut previous = clock.now();
+                    for _ in 0..20000 {
+                        let now = clock.now();
+                        assert!(now >= previous);
+                        previous = now;
+                    }
+                })
+            })
+            .collect();
+
+        for toggler in togglers {
+            toggler.join().unwrap();
+        }
+        for reader in readers {
+            reader.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_clock_id_sources_advance() {
+        for id in [ClockId::Monotonic, ClockId::MonotonicRaw, ClockId::Boottime] {
+            let clock = Clock::with_clock_id(id);
+            let start = clock.now();
+            ::std::thread::sleep(Duration::from_millis(50));
+            assert!(clock.now() > start);
+        }
+    }
 }
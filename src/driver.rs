@@ -0,0 +1,308 @@
+use crate::clock::{MonotonicClock, TimeInstant, TimeSource};
+use crate::epoch::Epoch;
+use ::core::marker::PhantomData;
+#[cfg(target_has_atomic = "64")]
+use ::core::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+use ::core::time::Duration;
+
+/// A platform timer that can drive a [TimeSource] or a [DriverClock] without
+/// requiring `std`.
+///
+/// The rest of this crate only ever calls [Driver::now], so a platform just
+/// has to expose a free-running tick counter and its frequency to give a
+/// target with no OS clock (e.g. a microcontroller reading a hardware timer
+/// peripheral) something to measure time against.
+pub trait Driver: Copy + Clone + ::core::fmt::Debug {
+    /// The tick frequency of this driver, in Hz. Must not be zero.
+    const TICK_HZ: u64;
+
+    /// Returns the current, free-running tick count. Must never go backwards.
+    fn now(&self) -> u64;
+}
+
+fn ticks_to_duration<D: Driver>(ticks: u64) -> Duration {
+    Duration::from_secs(ticks / D::TICK_HZ) + Duration::from_nanos((ticks % D::TICK_HZ) * 1_000_000_000 / D::TICK_HZ)
+}
+
+/// A point in time expressed in ticks of a [Driver] `D`.
+///
+/// The `D` parameter pins a [Ticks] value to the driver (and therefore the
+/// tick frequency) it was read from, so ticks from two different drivers
+/// can't accidentally be compared.
+pub struct Ticks<D: Driver>(u64, PhantomData<fn() -> D>);
+
+impl<D: Driver> Ticks<D> {
+    /// Wraps a raw tick count.
+    #[inline]
+    pub fn from_ticks(ticks: u64) -> Self {
+        Self(ticks, PhantomData)
+    }
+}
+
+impl<D: Driver> Clone for Ticks<D> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<D: Driver> Copy for Ticks<D> {}
+
+impl<D: Driver> ::core::fmt::Debug for Ticks<D> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        f.debug_tuple("Ticks").field(&self.0).finish()
+    }
+}
+
+impl<D: Driver> PartialEq for Ticks<D> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<D: Driver> Eq for Ticks<D> {}
+
+impl<D: Driver> PartialOrd for Ticks<D> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<D: Driver> Ord for Ticks<D> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<D: Driver> TimeInstant for Ticks<D> {
+    #[inline]
+    fn duration_since(&self, earlier: Self) -> Duration {
+        ticks_to_duration::<D>(self.0.saturating_sub(earlier.0))
+    }
+
+    #[inline]
+    fn saturating_sub(&self, earlier: Self) -> Duration {
+        ticks_to_duration::<D>(self.0.saturating_sub(earlier.0))
+    }
+}
+
+/// A [TimeSource] backed by a platform [Driver].
+///
+/// This is the `no_std` counterpart to [SystemTimeSource](crate::SystemTimeSource):
+/// use it where a hardware tick counter stands in for `std::time::Instant`.
+#[derive(Debug, Copy, Clone)]
+pub struct DriverTimeSource<D: Driver>(D);
+
+impl<D: Driver> DriverTimeSource<D> {
+    /// Creates a time source backed by the given driver.
+    #[inline]
+    pub fn new(driver: D) -> Self {
+        Self(driver)
+    }
+}
+
+impl<D: Driver> TimeSource for DriverTimeSource<D> {
+    type Instant = Ticks<D>;
+
+    #[inline]
+    fn now(&self) -> Self::Instant {
+        Ticks::from_ticks(self.0.now())
+    }
+}
+
+// See the matching constants and comment on InnerClock in clock.rs: splitting
+// "not running" from "stopped_at_ticks is valid" into separate states keeps
+// a reader from ever observing a stale frozen time, whether that's a stale
+// stop (STOPPING) or a stale resume (RESUMING).
+#[cfg(target_has_atomic = "64")]
+const RUNNING: u8 = 0;
+#[cfg(target_has_atomic = "64")]
+const STOPPING: u8 = 1;
+#[cfg(target_has_atomic = "64")]
+const STOPPED: u8 = 2;
+#[cfg(target_has_atomic = "64")]
+const RESUMING: u8 = 3;
+
+/// A lock-free monotonic clock driven by a [Driver], usable without `std`.
+///
+/// This is the `no_std` counterpart to [Clock](crate::Clock): it supports
+/// `start`/`stop`/`resume`/`reset` and implements [MonotonicClock], but -
+/// since there's no `alloc` to reach for here - isn't `Arc`-shared the way
+/// [Clock](crate::Clock) is, and doesn't support
+/// [Clock::adjust](crate::Clock::adjust)'s NTP-style slewing.
+///
+/// Requires `target_has_atomic = "64"` (64-bit atomics with compare-and-swap)
+/// and so isn't available on targets like `thumbv6m-none-eabi` (Cortex-M0),
+/// which have neither. [DriverTimeSource] has no such requirement and works
+/// on those targets; only the `start`/`stop`/`resume` bookkeeping here needs
+/// the wider atomics.
+#[cfg(target_has_atomic = "64")]
+#[derive(Debug)]
+pub struct DriverClock<D: Driver> {
+    driver: D,
+    origin: u64,
+    epoch: Epoch,
+    // Ticks-since-`origin` treated as this clock's zero point, mutated by
+    // `start()`/`reset()`/`resume()`.
+    base_ticks: AtomicU64,
+    // RUNNING, STOPPING, STOPPED or RESUMING; see the constants above.
+    state: AtomicU8,
+    // Ticks-since-`origin` latched at the moment `stop()` was called; only
+    // authoritative while `state` is STOPPED (and, transiently, RESUMING).
+    stopped_at_ticks: AtomicU64,
+}
+
+#[cfg(target_has_atomic = "64")]
+impl<D: Driver> DriverClock<D> {
+    /// Creates a new clock driven by `driver`, anchored to [Epoch::from_zero].
+    pub fn new(driver: D) -> Self {
+        Self::with_epoch(driver, Epoch::from_zero())
+    }
+
+    /// Creates a new clock driven by `driver`, anchored to the given epoch.
+    pub fn with_epoch(driver: D, epoch: Epoch) -> Self {
+        Self {
+            origin: driver.now(),
+            driver,
+            epoch,
+            base_ticks: AtomicU64::new(0),
+            state: AtomicU8::new(RUNNING),
+            stopped_at_ticks: AtomicU64::new(0),
+        }
+    }
+
+    fn raw_ticks(&self) -> u64 {
+        self.driver.now().saturating_sub(self.origin)
+    }
+
+    // Frozen at `stopped_at_ticks` while STOPPED, and still frozen through
+    // RESUMING until `resume()` has folded the paused interval into
+    // `base_ticks`. STOPPING is *not* frozen - see the comment on the
+    // equivalent method on InnerClock in clock.rs.
+    fn effective_raw_ticks(&self) -> u64 {
+        match self.state.load(Ordering::SeqCst) {
+            STOPPED | RESUMING => self.stopped_at_ticks.load(Ordering::SeqCst),
+            _ => self.raw_ticks(),
+        }
+    }
+
+    /// Reset the clock to zero.
+    pub fn reset(&self) {
+        self.base_ticks.store(self.raw_ticks(), Ordering::SeqCst);
+        self.state.store(RUNNING, Ordering::SeqCst);
+    }
+
+    /// Start the clock.
+    pub fn start(&self) {
+        self.base_ticks.store(self.raw_ticks(), Ordering::SeqCst);
+        self.state.store(RUNNING, Ordering::SeqCst);
+    }
+
+    /// Stop the clock if it's running, otherwise does nothing. Returns the
+    /// duration the clock was running for.
+    pub fn stop(&self) -> Option<Duration> {
+        let raw = self.raw_ticks();
+        if self.state.compare_exchange(RUNNING, STOPPING, Ordering::SeqCst, Ordering::SeqCst).is_ok() {
+            self.stopped_at_ticks.store(raw, Ordering::SeqCst);
+            self.state.store(STOPPED, Ordering::SeqCst);
+        }
+        let stopped_at = self.stopped_at_ticks.load(Ordering::SeqCst);
+        Some(ticks_to_duration::<D>(stopped_at.saturating_sub(self.base_ticks.load(Ordering::SeqCst))))
+    }
+
+    /// Resumes a paused clock, excluding the paused interval from `now()`.
+    /// Returns the duration the clock was paused for. If the clock is not
+    /// stopped, this does nothing.
+    pub fn resume(&self) -> Option<Duration> {
+        // Move through RESUMING (still frozen, same as STOPPED) while
+        // `base_ticks` is caught up, only publishing RUNNING once that's
+        // done - see the comment on the equivalent method on InnerClock in
+        // clock.rs.
+        let was_stopped = self.state.compare_exchange(STOPPED, RESUMING, Ordering::SeqCst, Ordering::SeqCst).is_ok();
+        if was_stopped {
+            // Read only after winning the CAS, as in InnerClock::resume.
+            let stopped_at = self.stopped_at_ticks.load(Ordering::SeqCst);
+            let paused = self.raw_ticks().saturating_sub(stopped_at);
+            self.base_ticks.fetch_add(paused, Ordering::SeqCst);
+            self.state.store(RUNNING, Ordering::SeqCst);
+            Some(ticks_to_duration::<D>(paused))
+        } else {
+            Some(Duration::new(0, 0))
+        }
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl<D: Driver> MonotonicClock for DriverClock<D> {
+    fn epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    fn now(&self) -> Duration {
+        let base = self.base_ticks.load(Ordering::SeqCst);
+        ticks_to_duration::<D>(self.effective_raw_ticks().saturating_sub(base))
+    }
+
+    fn is_ticking(&self) -> bool {
+        !matches!(self.state.load(Ordering::SeqCst), STOPPED | RESUMING)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Copy, Clone)]
+    struct TestDriver(u64);
+
+    impl Driver for TestDriver {
+        const TICK_HZ: u64 = 1_000;
+
+        fn now(&self) -> u64 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_driver_time_source_converts_ticks_to_duration() {
+        let source = DriverTimeSource::new(TestDriver(0));
+        let start = source.now();
+
+        let source = DriverTimeSource::new(TestDriver(2_500));
+        let end = source.now();
+
+        assert_eq!(end.duration_since(start), Duration::from_millis(2_500));
+    }
+
+    #[test]
+    #[cfg(target_has_atomic = "64")]
+    fn test_driver_clock_stop_resume_excludes_paused_duration() {
+        let ticks = ::core::cell::Cell::new(0u64);
+
+        #[derive(Debug, Copy, Clone)]
+        struct CellDriver<'a>(&'a ::core::cell::Cell<u64>);
+
+        impl Driver for CellDriver<'_> {
+            const TICK_HZ: u64 = 1_000;
+
+            fn now(&self) -> u64 {
+                self.0.get()
+            }
+        }
+
+        let clock = DriverClock::new(CellDriver(&ticks));
+
+        ticks.set(3_000);
+        let before_stop = clock.now();
+        assert_eq!(before_stop, Duration::from_secs(3));
+
+        clock.stop();
+        ticks.set(103_000);
+        let paused_for = clock.resume().unwrap();
+        assert_eq!(paused_for, Duration::from_secs(100));
+        assert_eq!(clock.now(), before_stop);
+    }
+}
@@ -1,13 +1,18 @@
-
-
+#[cfg(feature = "std")]
 use ::std::time::Duration;
 
+#[cfg(not(feature = "std"))]
+use ::core::time::Duration;
+
 /// Provides a starting timestamp in nanoseconds from UNIX_EPOCH.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Epoch(Duration);
 
 impl Epoch {
     /// Returns the current time as a UnixTimeStamp.
+    ///
+    /// Requires the `std` feature, since it reads `SystemTime`.
+    #[cfg(feature = "std")]
     #[inline]
     pub fn from_unix() -> Self {
         Self(::std::time::SystemTime::now().duration_since(::std::time::UNIX_EPOCH).unwrap())
@@ -27,14 +32,15 @@ impl Epoch {
 
 }
 
-impl ::std::default::Default for Epoch {
+#[cfg(feature = "std")]
+impl ::core::default::Default for Epoch {
     #[inline]
     fn default() -> Self {
         Self::from_unix()
     }
 }
 
-impl ::std::ops::Add for Epoch {
+impl ::core::ops::Add for Epoch {
     type Output = Self;
 
     #[inline]
@@ -43,7 +49,7 @@ impl ::std::ops::Add for Epoch {
     }
 }
 
-impl ::std::ops::Sub for Epoch {
+impl ::core::ops::Sub for Epoch {
     type Output = Self;
 
     #[inline]
@@ -52,7 +58,7 @@ impl ::std::ops::Sub for Epoch {
     }
 }
 
-impl ::std::ops::Add<Duration> for Epoch {
+impl ::core::ops::Add<Duration> for Epoch {
     type Output = Self;
 
     #[inline]
@@ -61,7 +67,7 @@ impl ::std::ops::Add<Duration> for Epoch {
     }
 }
 
-impl ::std::ops::Sub<Duration> for Epoch {
+impl ::core::ops::Sub<Duration> for Epoch {
     type Output = Self;
 
     #[inline]
@@ -70,36 +76,35 @@ impl ::std::ops::Sub<Duration> for Epoch {
     }
 }
 
-impl ::std::ops::AddAssign for Epoch {
+impl ::core::ops::AddAssign for Epoch {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
         self.0 += rhs.0;
     }
 }
 
-impl ::std::ops::SubAssign for Epoch {
+impl ::core::ops::SubAssign for Epoch {
     #[inline]
     fn sub_assign(&mut self, rhs: Self) {
         self.0 -= rhs.0;
     }
 }
 
-impl ::std::ops::AddAssign<Duration> for Epoch {
+impl ::core::ops::AddAssign<Duration> for Epoch {
     #[inline]
     fn add_assign(&mut self, rhs: Duration) {
         self.0 += rhs;
     }
 }
 
-impl ::std::ops::SubAssign<Duration> for Epoch {
+impl ::core::ops::SubAssign<Duration> for Epoch {
     #[inline]
     fn sub_assign(&mut self, rhs: Duration) {
         self.0 -= rhs;
     }
 }
 
-
-impl ::std::ops::Deref for Epoch {
+impl ::core::ops::Deref for Epoch {
     type Target = Duration;
 
     #[inline]
@@ -108,15 +113,15 @@ impl ::std::ops::Deref for Epoch {
     }
 }
 
-impl ::std::ops::DerefMut for Epoch {
+impl ::core::ops::DerefMut for Epoch {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
     }
 }
 
-impl ::std::fmt::Display for Epoch {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+impl ::core::fmt::Display for Epoch {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}", self.0.as_secs() as f64 + self.0.subsec_nanos() as f64 * 1e-9)
     }
 }
@@ -5,7 +5,7 @@
 //!
 //! ## Example
 //! ```
-//! use monotonic_clock::Clock;
+//! use monotonic_clock::{Clock, MonotonicClock};
 //! use std::thread;
 //! use std::time::Duration;
 //! let clock = Clock::new();
@@ -14,10 +14,33 @@
 //! let end = clock.now();
 //! assert!(end - start >= Duration::from_millis(100));
 //! ```
+//!
+//! ## `no_std` support
+//!
+//! The `std` feature is on by default and provides [Clock] and the rest of
+//! the `std::time::Instant`/OS-clock-backed [TimeSource]s above. Building
+//! with `default-features = false` drops all of that (it needs `Arc` and
+//! threads) but keeps [Epoch], [MonotonicClock], [TimeInstant] and
+//! [TimeSource] available, along with [driver::Driver] - a small trait for
+//! plugging in a platform timer (e.g. a hardware tick counter on a
+//! microcontroller). [driver::DriverTimeSource] turns one into a
+//! [TimeSource], and - on targets with 64-bit atomics - [driver::DriverClock]
+//! turns one into a full start/stop/resume [MonotonicClock], built from
+//! plain atomics so it needs no allocator or OS thread.
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
 
 mod clock;
+mod driver;
 mod epoch;
 
-pub use clock::{Clock, MonotonicClock};
+#[cfg(feature = "std")]
+pub use clock::{
+    Clock, ClockId, ClockIdTimeSource, CoarseInstant, CoarseTimeSource, MockInstant, MockTimeSource, Sign,
+    SystemTimeSource, DEFAULT_SLEW_PPM, MAX_SLEW_PPM, MAX_SLEW_WINDOW,
+};
+pub use clock::{MonotonicClock, TimeInstant, TimeSource};
+pub use driver::{Driver, DriverTimeSource, Ticks};
+#[cfg(target_has_atomic = "64")]
+pub use driver::DriverClock;
 pub use epoch::Epoch;